@@ -60,5 +60,47 @@ fn main() {
     let remaining = target_hours - total_work;
     println!("  Remaining to target: {}", remaining.format());
 
+    // Example: days and weeks, and the extended "dd:hh:mm:ss" format
+    println!("\n7. Days and weeks:");
+    let uptime = Duration::from_days(9) + Duration::from_hms(3, 15, 0);
+    println!("  from_days(9) + 3h15m: {}", uptime.format_extended());
+    println!("  days_part(): {}, hours_part(): {}", uptime.days_part(), uptime.hours_part());
+    println!("  as_weeks(): {}", Duration::from_days(10).as_weeks());
+
+    // Example: human-friendly parsing and formatting
+    println!("\n8. Human-friendly strings:");
+    let meeting = Duration::parse_human("1h 30m 15s").unwrap();
+    println!("  parse_human(\"1h 30m 15s\") -> {} seconds", meeting.as_seconds());
+    println!("  format_human(): {}", meeting.format_human());
+
+    // Example: configurable format descriptors
+    println!("\n9. format_with patterns:");
+    println!("  \"%Hh%Mm\":   {}", meeting.format_with("%Hh%Mm").unwrap());
+    println!("  \"%h:%M:%S\": {}", meeting.format_with("%h:%M:%S").unwrap());
+
+    // Example: scalar multiplication/division, ratios, and summing durations
+    println!("\n10. Scaling and ratios:");
+    let lap = Duration::from_seconds(90);
+    println!("  {} * 3 = {}", lap.format(), (lap * 3).format());
+    println!("  {} / 3 = {}", lap.format(), (lap / 3).format());
+    println!("  {} / {} = {}", (lap * 3).format(), lap.format(), (lap * 3) / lap);
+
+    let laps = [lap, lap, lap];
+    let total_lap_time: Duration = laps.iter().copied().sum();
+    println!("  sum of 3 laps: {}", total_lap_time.format());
+
+    // Example: checked arithmetic that reports overflow instead of saturating
+    println!("\n11. Checked arithmetic:");
+    match Duration::from_seconds(u64::MAX).checked_add(Duration::from_seconds(1)) {
+        Some(d) => println!("  checked_add succeeded: {}", d.format()),
+        None => println!("  checked_add(u64::MAX, 1s) -> None (would have overflowed)"),
+    }
+
+    // Example: recurring-interval schedule ticks
+    println!("\n12. Recurring intervals:");
+    for tick in Duration::from_minutes(15).iter_multiples().take(4) {
+        println!("  tick: {} ({} minutes)", tick.format(), tick.as_minutes());
+    }
+
     println!("\n=== End ===");
 }
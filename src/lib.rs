@@ -5,13 +5,24 @@
 //! `simple_duration` is a crate that provides a "simple and minimal dependency" second-precision Duration type for Rust.
 //! It's optimized for everyday "hours, minutes, seconds" handling and embedded environments (no_std).
 //!
+//! ## Breaking changes
+//!
+//! - **`hours_part()` is now day-relative (0-23)**: it used to return the unbounded total hours
+//!   (what [`Duration::as_hours`] returns today). Callers relying on the old unbounded value —
+//!   e.g. to display elapsed time past 24 hours — must switch to `as_hours()`; `format()` is
+//!   unaffected since it already used the unbounded value internally. This should be treated as
+//!   a major-version-bump change by consumers pinning this crate.
+//!
 //! ## Features
 //!
 //! - **Simple time representation in seconds**: Specialized for use cases that don't require high precision like milliseconds or nanoseconds
-//! - **Intuitive creation and formatting**: Easy creation from hours/minutes/seconds and conversion to `"hh:mm:ss"` format strings
-//! - **String parsing support**: Can create Duration objects from `"hh:mm:ss"` format strings
-//! - **Addition and subtraction operations**: Duration objects can be added and subtracted (results never become negative)
-//! - **SystemTime integration**: Can create Duration from two `SystemTime` instances (when `std` feature is enabled)
+//! - **Intuitive creation and formatting**: Easy creation from weeks/days/hours/minutes/seconds and conversion to `"hh:mm:ss"` / `"dd:hh:mm:ss"` format strings
+//! - **String parsing support**: Can create Duration objects from `"hh:mm:ss"` and `"dd:hh:mm:ss"` format strings, plus human-friendly strings like `"1h 30m 15s"` via `parse_human`
+//! - **Configurable formatting**: `format_with` renders custom layouts from a compact `%H`/`%M`/`%S`-style pattern
+//! - **Arithmetic operations**: Duration objects can be added, subtracted, scaled by a scalar (`Mul`/`Div`), divided into a ratio (`Div<Duration>`), and summed over an iterator
+//! - **Checked and saturating arithmetic**: `checked_add`/`checked_sub`/`checked_mul` return `None` on overflow or underflow and `checked_div` returns `None` for a zero divisor; the saturating variants clamp instead
+//! - **Recurring intervals**: `iter_multiples` yields an iterator over successive multiples of a Duration for schedule ticks
+//! - **SystemTime and `std::time::Duration` integration**: Can create Duration from two `SystemTime` instances, and convert to/from `std::time::Duration` (when `std` feature is enabled)
 //! - **no_std support & minimal dependencies**: Safe to use in embedded projects or projects that want to minimize dependencies
 //! - **Safe error handling**: Failures like string parsing return explicit errors via Option/Result without panicking
 //!
@@ -32,27 +43,40 @@
 //! // Create from seconds
 //! let duration = Duration::from_seconds(3661); // 1 hour 1 minute 1 second
 //!
+//! // Create from days or weeks
+//! let duration = Duration::from_days(2);
+//! let duration = Duration::from_weeks(1);
+//!
 //! // Create from string
 //! let duration = Duration::parse("01:30:45").unwrap();
 //!
+//! // Create from a human-friendly string
+//! let duration = Duration::parse_human("1h 30m 15s").unwrap();
+//!
 //! // Format
-//! assert_eq!(duration.format(), "01:30:45");
+//! assert_eq!(duration.format(), "01:30:15");
+//! assert_eq!(duration.format_human(), "1h 30m 15s");
 //!
 //! // Get total amounts in each unit
-//! assert_eq!(duration.as_seconds(), 5445);
+//! assert_eq!(duration.as_seconds(), 5415);
 //! assert_eq!(duration.as_minutes(), 90); // 90 minutes
 //! assert_eq!(duration.as_hours(), 1); // 1 hour (truncated)
 //!
 //! // Get each component (in h:m:s format)
-//! assert_eq!(duration.seconds_part(), 45); // seconds component (0-59)
+//! assert_eq!(duration.seconds_part(), 15); // seconds component (0-59)
 //! assert_eq!(duration.minutes_part(), 30);   // minutes component (0-59)
-//! assert_eq!(duration.hours_part(), 1);      // hours component
+//! assert_eq!(duration.hours_part(), 1);      // hours component (0-23)
 //!
 //! // Arithmetic operations
 //! let d1 = Duration::from_seconds(100);
 //! let d2 = Duration::from_seconds(50);
 //! let sum = d1 + d2; // 150 seconds
 //! let diff = d1 - d2; // 50 seconds
+//! let scaled = d1 * 3; // 300 seconds
+//! let ratio = d1 / d2; // 2.0
+//!
+//! // Checked arithmetic (returns None instead of saturating on overflow)
+//! assert_eq!(d1.checked_add(d2), Some(sum));
 //! ```
 
 #[cfg(feature = "std")]
@@ -62,9 +86,15 @@ use std::time::SystemTime;
 extern crate alloc;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, format};
+use alloc::{string::String, vec::Vec, format};
+
+use core::ops::{Add, Div, Mul, Sub};
 
-use core::ops::{Add, Sub};
+/// Number of seconds in a day
+pub const SECONDS_PER_DAY: u64 = 86400;
+
+/// Number of seconds in a week
+pub const SECONDS_PER_WEEK: u64 = 604800;
 
 /// Simple Duration type with second precision
 ///
@@ -82,6 +112,9 @@ pub enum DurationError {
     InvalidFormat,
     /// Invalid value for hours, minutes, or seconds
     InvalidValue,
+    /// A `std::time::Duration` had non-zero sub-second precision that would be silently dropped
+    #[cfg(feature = "std")]
+    PrecisionLoss,
 }
 
 impl Duration {
@@ -139,6 +172,39 @@ impl Duration {
         }
     }
 
+    /// Create Duration from days
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_days(2);
+    /// assert_eq!(duration.as_seconds(), 172800);
+    /// assert_eq!(duration.days_part(), 2);
+    /// ```
+    pub fn from_days(days: u64) -> Self {
+        Self {
+            seconds: days * SECONDS_PER_DAY,
+        }
+    }
+
+    /// Create Duration from weeks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_weeks(1);
+    /// assert_eq!(duration.as_seconds(), 604800);
+    /// ```
+    pub fn from_weeks(weeks: u64) -> Self {
+        Self {
+            seconds: weeks * SECONDS_PER_WEEK,
+        }
+    }
+
     /// Create Duration from hours, minutes, and seconds
     ///
     /// # Examples
@@ -234,6 +300,34 @@ impl Duration {
         self.seconds / 3600
     }
 
+    /// Get total days (truncated)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(90000); // 1 day 1 hour
+    /// assert_eq!(duration.as_days(), 1);
+    /// ```
+    pub fn as_days(&self) -> u64 {
+        self.seconds / SECONDS_PER_DAY
+    }
+
+    /// Get total weeks (truncated)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_days(10);
+    /// assert_eq!(duration.as_weeks(), 1);
+    /// ```
+    pub fn as_weeks(&self) -> u64 {
+        self.seconds / SECONDS_PER_WEEK
+    }
+
     /// Get seconds component (0-59)
     ///
     /// # Examples
@@ -268,7 +362,13 @@ impl Duration {
         (self.seconds % 3600) / 60
     }
 
-    /// Get hours component (0-âˆž)
+    /// Get hours component (0-23)
+    ///
+    /// **Breaking change**: prior to the introduction of [`Duration::days_part`], this method
+    /// returned the *unbounded* total hours (equivalent to [`Duration::as_hours`]). It now wraps
+    /// at 24 so that `days_part`/`hours_part` together decompose a Duration the same way
+    /// `hours_part`/`minutes_part`/`seconds_part` already did for smaller units. Callers that
+    /// want the old unbounded value should use [`Duration::as_hours`] instead.
     ///
     /// # Examples
     ///
@@ -278,11 +378,28 @@ impl Duration {
     /// let duration = Duration::from_seconds(3661); // 1 hour 1 minute 1 second
     /// assert_eq!(duration.hours_part(), 1);
     ///
-    /// let duration = Duration::from_seconds(7200); // 2 hours
-    /// assert_eq!(duration.hours_part(), 2);
+    /// let duration = Duration::from_seconds(90000); // 1 day 1 hour
+    /// assert_eq!(duration.hours_part(), 1);
+    /// assert_eq!(duration.days_part(), 1);
+    /// assert_eq!(duration.as_hours(), 25); // the old hours_part() behavior
     /// ```
     pub fn hours_part(&self) -> u64 {
-        self.seconds / 3600
+        (self.seconds % SECONDS_PER_DAY) / 3600
+    }
+
+    /// Get days component (0-∞)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(90000); // 1 day 1 hour
+    /// assert_eq!(duration.days_part(), 1);
+    /// assert_eq!(duration.hours_part(), 1);
+    /// ```
+    pub fn days_part(&self) -> u64 {
+        self.seconds / SECONDS_PER_DAY
     }
 
     /// Format as "hh:mm:ss" string
@@ -296,7 +413,235 @@ impl Duration {
     /// assert_eq!(duration.format(), "01:05:30");
     /// ```
     pub fn format(&self) -> String {
-        format!("{:02}:{:02}:{:02}", self.hours_part(), self.minutes_part(), self.seconds_part())
+        format!("{:02}:{:02}:{:02}", self.as_hours(), self.minutes_part(), self.seconds_part())
+    }
+
+    /// Format as "dd:hh:mm:ss" string, spanning days as well as hours/minutes/seconds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(90000); // 1 day 1 hour
+    /// assert_eq!(duration.format_extended(), "01:01:00:00");
+    /// ```
+    pub fn format_extended(&self) -> String {
+        format!(
+            "{:02}:{:02}:{:02}:{:02}",
+            self.days_part(),
+            self.hours_part(),
+            self.minutes_part(),
+            self.seconds_part()
+        )
+    }
+
+    /// Parse Duration from "dd:hh:mm:ss" format string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::parse_extended("01:01:00:00").unwrap();
+    /// assert_eq!(duration.as_seconds(), 90000);
+    ///
+    /// assert!(Duration::parse_extended("invalid").is_err());
+    /// ```
+    pub fn parse_extended(s: &str) -> Result<Self, DurationError> {
+        let parts: Vec<&str> = s.split(':').collect();
+        if parts.len() != 4 {
+            return Err(DurationError::InvalidFormat);
+        }
+
+        let days = parts[0].parse::<u64>().map_err(|_| DurationError::InvalidValue)?;
+        let hours = parts[1].parse::<u64>().map_err(|_| DurationError::InvalidValue)?;
+        let minutes = parts[2].parse::<u64>().map_err(|_| DurationError::InvalidValue)?;
+        let seconds = parts[3].parse::<u64>().map_err(|_| DurationError::InvalidValue)?;
+
+        if hours >= 24 || minutes >= 60 || seconds >= 60 {
+            return Err(DurationError::InvalidValue);
+        }
+
+        let seconds = days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|s| s.checked_add(hours * 3600))
+            .and_then(|s| s.checked_add(minutes * 60))
+            .and_then(|s| s.checked_add(seconds))
+            .ok_or(DurationError::InvalidValue)?;
+
+        Ok(Self { seconds })
+    }
+
+    /// Format using a compact descriptor pattern, for output forms beyond the fixed `format()`/`format_extended()` layouts
+    ///
+    /// The pattern is scanned left-to-right, copying literal characters through and substituting
+    /// on `%` tokens: `%H` (total hours, unbounded), `%M`/`%S` (zero-padded minutes/seconds
+    /// component, 0-59), `%h`/`%m`/`%s` (total value as that unit, via `as_hours`/`as_minutes`/
+    /// `as_seconds`), `%d` (total days, via `as_days`), and `%%` (a literal `%`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_hms(1, 30, 0);
+    /// assert_eq!(duration.format_with("%Hh%Mm").unwrap(), "1h30m");
+    /// assert_eq!(duration.format_with("%h:%M:%S").unwrap(), "1:30:00");
+    ///
+    /// assert!(duration.format_with("%x").is_err());
+    /// ```
+    pub fn format_with(&self, pattern: &str) -> Result<String, DurationError> {
+        let mut result = String::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('H') => result.push_str(&format!("{}", self.as_hours())),
+                Some('M') => result.push_str(&format!("{:02}", self.minutes_part())),
+                Some('S') => result.push_str(&format!("{:02}", self.seconds_part())),
+                Some('h') => result.push_str(&format!("{}", self.as_hours())),
+                Some('m') => result.push_str(&format!("{}", self.as_minutes())),
+                Some('s') => result.push_str(&format!("{}", self.as_seconds())),
+                Some('d') => result.push_str(&format!("{}", self.as_days())),
+                Some('%') => result.push('%'),
+                _ => return Err(DurationError::InvalidFormat),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parse a human-friendly duration string such as `"1h 30m 15s"`, `"2days 4hours"`, or `"90m"`
+    ///
+    /// The string is scanned in a single pass: a run of digits is accumulated as a number,
+    /// then a unit suffix multiplies and adds it into the running second total. Whitespace
+    /// between a value and its unit, and between terms, is optional. Accepted units are
+    /// `s`/`sec`/`secs`/`second`/`seconds`, `m`/`min`/`mins`/`minute`/`minutes`,
+    /// `h`/`hr`/`hrs`/`hour`/`hours`, `d`/`day`/`days`, and `w`/`week`/`weeks`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::parse_human("1h 30m 15s").unwrap();
+    /// assert_eq!(duration.as_seconds(), 3600 + 30 * 60 + 15);
+    ///
+    /// let duration = Duration::parse_human("90m").unwrap();
+    /// assert_eq!(duration.as_seconds(), 5400);
+    ///
+    /// assert!(Duration::parse_human("").is_err());
+    /// assert!(Duration::parse_human("10").is_err());
+    /// assert!(Duration::parse_human("h").is_err());
+    /// ```
+    pub fn parse_human(s: &str) -> Result<Self, DurationError> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        let mut total: u64 = 0;
+        let mut saw_term = false;
+
+        while i < bytes.len() {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if i >= bytes.len() {
+                break;
+            }
+
+            let value_start = i;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            if i == value_start {
+                return Err(DurationError::InvalidFormat);
+            }
+            let value = s[value_start..i]
+                .parse::<u64>()
+                .map_err(|_| DurationError::InvalidFormat)?;
+
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+
+            let unit_start = i;
+            while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+            if i == unit_start {
+                return Err(DurationError::InvalidFormat);
+            }
+            let unit = &s[unit_start..i];
+
+            let multiplier = match unit {
+                "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+                "d" | "day" | "days" => SECONDS_PER_DAY,
+                "w" | "week" | "weeks" => SECONDS_PER_WEEK,
+                _ => return Err(DurationError::InvalidFormat),
+            };
+
+            total = total.saturating_add(value.saturating_mul(multiplier));
+            saw_term = true;
+
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+        }
+
+        if !saw_term {
+            return Err(DurationError::InvalidFormat);
+        }
+
+        Ok(Self::from_seconds(total))
+    }
+
+    /// Format as a human-friendly string, emitting only the largest non-zero units
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// assert_eq!(Duration::from_seconds(3661).format_human(), "1h 1m 1s");
+    /// assert_eq!(Duration::from_seconds(90).format_human(), "1m 30s");
+    /// assert_eq!(Duration::zero().format_human(), "0s");
+    /// ```
+    pub fn format_human(&self) -> String {
+        let weeks = self.seconds / SECONDS_PER_WEEK;
+        let days = (self.seconds % SECONDS_PER_WEEK) / SECONDS_PER_DAY;
+        let hours = (self.seconds % SECONDS_PER_DAY) / 3600;
+        let minutes = (self.seconds % 3600) / 60;
+        let seconds = self.seconds % 60;
+
+        let mut result = String::new();
+        let push_part = |result: &mut String, value: u64, unit: &str| {
+            if value == 0 {
+                return;
+            }
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(&format!("{}{}", value, unit));
+        };
+
+        push_part(&mut result, weeks, "w");
+        push_part(&mut result, days, "d");
+        push_part(&mut result, hours, "h");
+        push_part(&mut result, minutes, "m");
+        push_part(&mut result, seconds, "s");
+
+        if result.is_empty() {
+            result.push_str("0s");
+        }
+
+        result
     }
 
     /// Create a zero Duration
@@ -322,6 +667,115 @@ impl Duration {
             seconds: self.seconds.saturating_sub(other.seconds),
         }
     }
+
+    /// Saturating scalar multiplication (prevents overflow)
+    pub fn saturating_mul(self, scalar: u64) -> Self {
+        Self {
+            seconds: self.seconds.saturating_mul(scalar),
+        }
+    }
+
+    /// Divide by a scalar, returning `None` instead of panicking when `scalar` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(100);
+    /// assert_eq!(duration.checked_div(4).unwrap().as_seconds(), 25);
+    /// assert_eq!(duration.checked_div(0), None);
+    /// ```
+    pub fn checked_div(self, scalar: u64) -> Option<Self> {
+        self.seconds.checked_div(scalar).map(|seconds| Self { seconds })
+    }
+
+    /// Checked addition, returning `None` instead of saturating on overflow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(100);
+    /// assert_eq!(duration.checked_add(Duration::from_seconds(50)).unwrap().as_seconds(), 150);
+    /// assert_eq!(Duration::from_seconds(u64::MAX).checked_add(duration), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.seconds.checked_add(other.seconds).map(|seconds| Self { seconds })
+    }
+
+    /// Checked subtraction, returning `None` instead of saturating to zero on underflow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(100);
+    /// assert_eq!(duration.checked_sub(Duration::from_seconds(50)).unwrap().as_seconds(), 50);
+    /// assert_eq!(Duration::from_seconds(50).checked_sub(duration), None);
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.seconds.checked_sub(other.seconds).map(|seconds| Self { seconds })
+    }
+
+    /// Checked scalar multiplication, returning `None` instead of saturating on overflow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(100);
+    /// assert_eq!(duration.checked_mul(3).unwrap().as_seconds(), 300);
+    /// assert_eq!(Duration::from_seconds(u64::MAX).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, scalar: u64) -> Option<Self> {
+        self.seconds.checked_mul(scalar).map(|seconds| Self { seconds })
+    }
+
+    /// Iterate over successive multiples of this Duration (1x, 2x, 3x, ...)
+    ///
+    /// Saturates at `u64::MAX` and then stops, rather than wrapping. Useful for generating
+    /// schedule ticks or cumulative checkpoints without pulling in a date library.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let ticks: Vec<Duration> = Duration::from_minutes(15).iter_multiples().take(4).collect();
+    /// assert_eq!(ticks[0].as_minutes(), 15);
+    /// assert_eq!(ticks[1].as_minutes(), 30);
+    /// assert_eq!(ticks[2].as_minutes(), 45);
+    /// assert_eq!(ticks[3].as_minutes(), 60);
+    /// ```
+    pub fn iter_multiples(self) -> DurationIter {
+        DurationIter {
+            step: self,
+            next: Some(self),
+        }
+    }
+}
+
+/// Iterator over successive multiples of a step `Duration`, produced by [`Duration::iter_multiples`]
+pub struct DurationIter {
+    step: Duration,
+    next: Option<Duration>,
+}
+
+impl Iterator for DurationIter {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let upcoming = current.saturating_add(self.step);
+        // Once saturation stops making progress (we're pinned at u64::MAX), stop the
+        // sequence instead of yielding the same value forever.
+        self.next = if upcoming == current { None } else { Some(upcoming) };
+        Some(current)
+    }
 }
 
 /// SystemTime conversion (only when std feature is enabled)
@@ -348,6 +802,75 @@ impl Duration {
             .ok()
             .map(|std_duration| Self::from_seconds(std_duration.as_secs()))
     }
+
+    /// Create Duration from a `std::time::Duration`, truncating any sub-second precision
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_std(std::time::Duration::from_millis(1500));
+    /// assert_eq!(duration.as_seconds(), 1);
+    /// ```
+    pub fn from_std(std_duration: std::time::Duration) -> Self {
+        Self::from_seconds(std_duration.as_secs())
+    }
+
+    /// Convert to a `std::time::Duration` with no fractional seconds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::Duration;
+    ///
+    /// let duration = Duration::from_seconds(90);
+    /// assert_eq!(duration.as_std(), std::time::Duration::from_secs(90));
+    /// ```
+    pub fn as_std(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.seconds)
+    }
+}
+
+/// `std::time::Duration` interoperability (only when `std` feature is enabled)
+///
+/// Converting from `std::time::Duration` truncates any sub-second precision;
+/// use [`Duration::try_from_std`] instead if losing that precision should be an error.
+#[cfg(feature = "std")]
+impl From<std::time::Duration> for Duration {
+    fn from(std_duration: std::time::Duration) -> Self {
+        Self::from_std(std_duration)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Duration> for std::time::Duration {
+    fn from(duration: Duration) -> Self {
+        duration.as_std()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Duration {
+    /// Fallibly create a Duration from a `std::time::Duration`, rejecting sub-second precision
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use simple_duration::{Duration, DurationError};
+    ///
+    /// let duration = Duration::try_from_std(std::time::Duration::from_secs(90)).unwrap();
+    /// assert_eq!(duration.as_seconds(), 90);
+    ///
+    /// let err = Duration::try_from_std(std::time::Duration::from_millis(1500)).unwrap_err();
+    /// assert_eq!(err, DurationError::PrecisionLoss);
+    /// ```
+    pub fn try_from_std(std_duration: std::time::Duration) -> Result<Self, DurationError> {
+        if std_duration.subsec_nanos() != 0 {
+            return Err(DurationError::PrecisionLoss);
+        }
+        Ok(Self::from_std(std_duration))
+    }
 }
 
 impl Add for Duration {
@@ -372,6 +895,48 @@ impl core::fmt::Display for Duration {
     }
 }
 
+impl Mul<u64> for Duration {
+    type Output = Self;
+
+    fn mul(self, scalar: u64) -> Self::Output {
+        self.saturating_mul(scalar)
+    }
+}
+
+impl Div<u64> for Duration {
+    type Output = Self;
+
+    /// Divide by a scalar
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalar` is zero, matching the behavior of integer division. Use
+    /// [`Duration::checked_div`] if the divisor may be zero.
+    fn div(self, scalar: u64) -> Self::Output {
+        Self {
+            seconds: self.seconds / scalar,
+        }
+    }
+}
+
+impl Div<Duration> for Duration {
+    type Output = f64;
+
+    /// Compute the ratio between two durations as an `f64`
+    ///
+    /// This is float division, not integer division: if `other` is zero this does not
+    /// panic, it returns `f64::INFINITY` (or `NaN` if `self` is also zero).
+    fn div(self, other: Duration) -> Self::Output {
+        self.seconds as f64 / other.seconds as f64
+    }
+}
+
+impl core::iter::Sum for Duration {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, duration| total.saturating_add(duration))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,4 +1091,186 @@ mod tests {
         let duration = Duration::from_system_time_diff(start, end).unwrap();
         assert_eq!(duration.as_seconds(), 100);
     }
+
+    #[test]
+    fn test_parse_human() {
+        assert_eq!(Duration::parse_human("1h 30m 15s").unwrap().as_seconds(), 3600 + 30 * 60 + 15);
+        assert_eq!(Duration::parse_human("2days 4hours").unwrap().as_seconds(), 2 * 86400 + 4 * 3600);
+        assert_eq!(Duration::parse_human("90m").unwrap().as_seconds(), 5400);
+        assert_eq!(Duration::parse_human("1week").unwrap().as_seconds(), 604800);
+        assert_eq!(Duration::parse_human("10s").unwrap().as_seconds(), 10);
+        assert_eq!(Duration::parse_human("1 h 1 m 1 s").unwrap().as_seconds(), 3661);
+
+        // Invalid inputs
+        assert!(Duration::parse_human("").is_err());
+        assert!(Duration::parse_human("10").is_err()); // number with no unit
+        assert!(Duration::parse_human("h").is_err()); // unit with no number
+        assert!(Duration::parse_human("1h garbage").is_err());
+        assert!(Duration::parse_human("1x").is_err()); // unknown unit
+    }
+
+    #[test]
+    fn test_format_human() {
+        assert_eq!(Duration::from_seconds(3661).format_human(), "1h 1m 1s");
+        assert_eq!(Duration::from_seconds(90).format_human(), "1m 30s");
+        assert_eq!(Duration::zero().format_human(), "0s");
+        assert_eq!(Duration::from_seconds(604800 + 86400).format_human(), "1w 1d");
+        assert_eq!(Duration::from_seconds(3600).format_human(), "1h");
+    }
+
+    #[test]
+    fn test_days_and_weeks() {
+        let d1 = Duration::from_days(2);
+        assert_eq!(d1.as_seconds(), 172800);
+        assert_eq!(d1.days_part(), 2);
+        assert_eq!(d1.hours_part(), 0);
+
+        let d2 = Duration::from_weeks(1);
+        assert_eq!(d2.as_seconds(), 604800);
+        assert_eq!(d2.as_days(), 7);
+        assert_eq!(d2.as_weeks(), 1);
+
+        // hours_part now reports 0-23, days_part carries the rest
+        let d3 = Duration::from_seconds(90000); // 1 day 1 hour
+        assert_eq!(d3.days_part(), 1);
+        assert_eq!(d3.hours_part(), 1);
+        assert_eq!(d3.as_hours(), 25);
+    }
+
+    #[test]
+    fn test_format_and_parse_extended() {
+        let duration = Duration::from_seconds(90000); // 1 day 1 hour
+        assert_eq!(duration.format_extended(), "01:01:00:00");
+
+        let parsed = Duration::parse_extended("01:01:00:00").unwrap();
+        assert_eq!(parsed.as_seconds(), 90000);
+
+        assert!(Duration::parse_extended("invalid").is_err());
+        assert!(Duration::parse_extended("1:2:3").is_err()); // wrong field count
+        assert!(Duration::parse_extended("01:24:00:00").is_err()); // hours out of range
+        assert!(Duration::parse_extended("01:00:60:00").is_err()); // minutes out of range
+        assert!(Duration::parse_extended("01:00:00:60").is_err()); // seconds out of range
+
+        // A day count that would overflow the total-seconds multiplication returns an
+        // error instead of panicking or silently wrapping.
+        assert!(Duration::parse_extended("300000000000000:00:00:00").is_err());
+    }
+
+    #[test]
+    fn test_scalar_mul_div() {
+        let duration = Duration::from_seconds(100);
+
+        // Scalar multiplication
+        assert_eq!((duration * 3).as_seconds(), 300);
+
+        // Scalar division
+        assert_eq!((duration / 4).as_seconds(), 25);
+
+        // Overflow saturates rather than panicking
+        let max_duration = Duration::from_seconds(u64::MAX);
+        assert_eq!((max_duration * 2).as_seconds(), u64::MAX);
+
+        // checked_div avoids the panic of `duration / 0`
+        assert_eq!(duration.checked_div(4).unwrap().as_seconds(), 25);
+        assert_eq!(duration.checked_div(0), None);
+    }
+
+    #[test]
+    fn test_duration_ratio() {
+        let d1 = Duration::from_seconds(100);
+        let d2 = Duration::from_seconds(50);
+
+        assert_eq!(d1 / d2, 2.0);
+        assert_eq!(d2 / d1, 0.5);
+
+        // Division by a zero Duration is float division: it yields infinity/NaN
+        // rather than panicking.
+        assert_eq!(d1 / Duration::zero(), f64::INFINITY);
+        assert!((Duration::zero() / Duration::zero()).is_nan());
+    }
+
+    #[test]
+    fn test_sum() {
+        let durations = [
+            Duration::from_seconds(10),
+            Duration::from_seconds(20),
+            Duration::from_seconds(30),
+        ];
+
+        let total: Duration = durations.iter().copied().sum();
+        assert_eq!(total.as_seconds(), 60);
+
+        let empty: Duration = core::iter::empty::<Duration>().sum();
+        assert_eq!(empty, Duration::zero());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_duration_interop() {
+        let std_duration = std::time::Duration::from_secs(90);
+        let duration = Duration::from_std(std_duration);
+        assert_eq!(duration.as_seconds(), 90);
+        assert_eq!(duration.as_std(), std_duration);
+
+        let duration: Duration = std_duration.into();
+        assert_eq!(duration.as_seconds(), 90);
+
+        let back: std::time::Duration = duration.into();
+        assert_eq!(back, std_duration);
+
+        // Sub-second precision is truncated by the infallible From conversion
+        let fractional = std::time::Duration::from_millis(1500);
+        assert_eq!(Duration::from(fractional).as_seconds(), 1);
+
+        // ...but rejected by the fallible try_from_std conversion
+        assert_eq!(Duration::try_from_std(std_duration).unwrap().as_seconds(), 90);
+        assert_eq!(Duration::try_from_std(fractional).unwrap_err(), DurationError::PrecisionLoss);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let d1 = Duration::from_seconds(100);
+        let d2 = Duration::from_seconds(50);
+
+        // Normal cases behave like the saturating variants
+        assert_eq!(d1.checked_add(d2).unwrap().as_seconds(), 150);
+        assert_eq!(d1.checked_sub(d2).unwrap().as_seconds(), 50);
+        assert_eq!(d1.checked_mul(3).unwrap().as_seconds(), 300);
+
+        // Overflow/underflow returns None instead of saturating
+        let max_duration = Duration::from_seconds(u64::MAX);
+        assert_eq!(max_duration.checked_add(d1), None);
+        assert_eq!(d2.checked_sub(d1), None);
+        assert_eq!(max_duration.checked_mul(2), None);
+    }
+
+    #[test]
+    fn test_iter_multiples() {
+        let ticks: Vec<Duration> = Duration::from_minutes(15).iter_multiples().take(4).collect();
+        assert_eq!(ticks.iter().map(Duration::as_minutes).collect::<Vec<_>>(), vec![15, 30, 45, 60]);
+
+        // The sequence saturates at u64::MAX and then stops rather than repeating forever
+        let near_max = Duration::from_seconds(u64::MAX - 1);
+        let tail: Vec<Duration> = near_max.iter_multiples().take(5).collect();
+        assert_eq!(tail, vec![
+            Duration::from_seconds(u64::MAX - 1),
+            Duration::from_seconds(u64::MAX),
+        ]);
+    }
+
+    #[test]
+    fn test_format_with() {
+        let duration = Duration::from_hms(1, 30, 0);
+
+        assert_eq!(duration.format_with("%Hh%Mm").unwrap(), "1h30m");
+        assert_eq!(duration.format_with("%h:%M:%S").unwrap(), "1:30:00");
+        assert_eq!(duration.format_with("%H:%M:%S").unwrap(), "1:30:00");
+        assert_eq!(duration.format_with("100%%").unwrap(), "100%");
+
+        let multi_day = Duration::from_seconds(90000); // 1 day 1 hour = 25 total hours
+        assert_eq!(multi_day.format_with("%d days %H:%M:%S").unwrap(), "1 days 25:00:00");
+
+        assert!(duration.format_with("%x").is_err()); // unknown token
+        assert!(duration.format_with("%").is_err()); // dangling token
+    }
 }